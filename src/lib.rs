@@ -0,0 +1,6 @@
+pub mod middleware;
+pub mod models;
+pub mod oauth;
+pub mod repositories;
+pub mod routes;
+pub mod telemetry;
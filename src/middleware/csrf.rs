@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use actix_web::body::EitherBody;
+use actix_web::cookie::{Cookie, SameSite};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::Method;
+use actix_web::{Error, HttpResponse};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use futures_util::future::{LocalBoxFuture, Ready, ready};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const NONCE_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 32;
+
+/// Header clients must echo back alongside the `csrf_token` cookie on
+/// unsafe methods.
+pub const CSRF_HEADER_NAME: &str = "X-CSRF-Token";
+
+/// Signing key and cookie attributes for the double-submit CSRF guard.
+///
+/// On safe (`GET`/`HEAD`/`OPTIONS`) requests the guard mints a fresh,
+/// HMAC-signed `csrf_token` cookie; on unsafe methods it requires the same
+/// token to be echoed back via [`CSRF_HEADER_NAME`] and rejects a missing
+/// or mismatched pair with `403`. The cookie is never `HttpOnly`, since the
+/// whole point of double-submit is that browser JS reads it back to set
+/// the header.
+///
+/// There is no per-path opt-out: machine-to-machine endpoints like
+/// `/oauth/token` are exempted by never being wrapped with this middleware
+/// in the first place (see `oauth_route::configure_routes`), not by a path
+/// allowlist inside the guard.
+#[derive(Clone)]
+pub struct CsrfConfig {
+    signing_key: Arc<[u8]>,
+    cookie_name: &'static str,
+    same_site: SameSite,
+    secure: bool,
+    path: &'static str,
+}
+
+impl CsrfConfig {
+    pub fn new(signing_key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            signing_key: Arc::from(signing_key.into().into_boxed_slice()),
+            cookie_name: "csrf_token",
+            same_site: SameSite::Strict,
+            secure: true,
+            path: "/",
+        }
+    }
+
+    /// Reads the HMAC signing key from `CSRF_SIGNING_KEY`. Panics at
+    /// startup if unset: an ephemeral per-process key would invalidate
+    /// every outstanding cookie on restart or across a multi-instance
+    /// deployment.
+    pub fn from_env() -> Self {
+        let key = std::env::var("CSRF_SIGNING_KEY").expect("CSRF_SIGNING_KEY must be set");
+        Self::new(key.into_bytes())
+    }
+
+    pub fn with_cookie_name(mut self, cookie_name: &'static str) -> Self {
+        self.cookie_name = cookie_name;
+        self
+    }
+
+    pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = same_site;
+        self
+    }
+
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    pub fn with_path(mut self, path: &'static str) -> Self {
+        self.path = path;
+        self
+    }
+
+    fn sign(&self, nonce: &[u8]) -> Vec<u8> {
+        let mut mac = HmacSha256::new_from_slice(&self.signing_key).expect("HMAC accepts any key length");
+        mac.update(nonce);
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    fn issue(&self) -> String {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let signature = self.sign(&nonce);
+
+        let mut token = Vec::with_capacity(NONCE_LEN + SIGNATURE_LEN);
+        token.extend_from_slice(&nonce);
+        token.extend_from_slice(&signature);
+        URL_SAFE_NO_PAD.encode(token)
+    }
+
+    fn issue_cookie(&self) -> Cookie<'static> {
+        Cookie::build(self.cookie_name, self.issue())
+            .path(self.path.to_string())
+            .same_site(self.same_site)
+            .secure(self.secure)
+            .http_only(false)
+            .finish()
+    }
+
+    /// Verifies that `token` carries a signature this key produced, in
+    /// constant time so a timing side channel cannot narrow down the
+    /// signing key byte by byte.
+    fn verify(&self, token: &str) -> bool {
+        let Ok(bytes) = URL_SAFE_NO_PAD.decode(token) else {
+            return false;
+        };
+        if bytes.len() != NONCE_LEN + SIGNATURE_LEN {
+            return false;
+        }
+        let (nonce, signature) = bytes.split_at(NONCE_LEN);
+        let expected = self.sign(nonce);
+        signature.ct_eq(&expected).into()
+    }
+}
+
+fn is_safe_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+impl<S, B> Transform<S, ServiceRequest> for CsrfConfig
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = CsrfGuard<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CsrfGuard {
+            service,
+            config: self.clone(),
+        }))
+    }
+}
+
+pub struct CsrfGuard<S> {
+    service: S,
+    config: CsrfConfig,
+}
+
+impl<S, B> Service<ServiceRequest> for CsrfGuard<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if is_safe_method(req.method()) {
+            let cookie = self.config.issue_cookie();
+            let fut = self.service.call(req);
+            return Box::pin(async move {
+                let mut response = fut.await?.map_into_left_body();
+                let _ = response.response_mut().add_cookie(&cookie);
+                Ok(response)
+            });
+        }
+
+        let cookie_token = req.cookie(self.config.cookie_name).map(|cookie| cookie.value().to_string());
+        let header_token = req
+            .headers()
+            .get(CSRF_HEADER_NAME)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let valid = match (&cookie_token, &header_token) {
+            (Some(cookie_token), Some(header_token)) => {
+                self.config.verify(cookie_token)
+                    && bool::from(cookie_token.as_bytes().ct_eq(header_token.as_bytes()))
+            }
+            _ => false,
+        };
+
+        if valid {
+            let fut = self.service.call(req);
+            Box::pin(async move { Ok(fut.await?.map_into_left_body()) })
+        } else {
+            Box::pin(async move { Ok(req.into_response(HttpResponse::Forbidden().finish()).map_into_right_body()) })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::{App, test, web};
+
+    use super::*;
+
+    fn config() -> CsrfConfig {
+        CsrfConfig::new(b"test-signing-key".to_vec())
+    }
+
+    #[test]
+    fn verify_accepts_a_token_issued_by_the_same_config() {
+        let config = config();
+        assert!(config.verify(&config.issue()));
+    }
+
+    #[test]
+    fn verify_rejects_a_token_signed_with_a_different_key() {
+        let token = CsrfConfig::new(b"key-a".to_vec()).issue();
+        assert!(!CsrfConfig::new(b"key-b".to_vec()).verify(&token));
+    }
+
+    #[test]
+    fn verify_rejects_malformed_tokens() {
+        assert!(!config().verify("not valid base64!!"));
+        assert!(!config().verify(&URL_SAFE_NO_PAD.encode(b"too-short")));
+    }
+
+    #[actix_web::test]
+    async fn safe_method_issues_a_csrf_cookie() {
+        let app = test::init_service(
+            App::new()
+                .wrap(config())
+                .route("/", web::get().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+
+        let resp = test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await;
+
+        assert!(resp.response().cookies().any(|cookie| cookie.name() == "csrf_token"));
+    }
+
+    #[actix_web::test]
+    async fn unsafe_method_without_a_token_is_rejected() {
+        let app = test::init_service(
+            App::new()
+                .wrap(config())
+                .route("/", web::post().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+
+        let resp = test::call_service(&app, test::TestRequest::post().uri("/").to_request()).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn unsafe_method_with_matching_cookie_and_header_succeeds() {
+        let config = config();
+        let token = config.issue();
+        let app = test::init_service(
+            App::new()
+                .wrap(config)
+                .route("/", web::post().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .cookie(Cookie::new("csrf_token", token.clone()))
+            .insert_header((CSRF_HEADER_NAME, token))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn unsafe_method_with_mismatched_cookie_and_header_is_rejected() {
+        let config = config();
+        let token = config.issue();
+        let app = test::init_service(
+            App::new()
+                .wrap(config)
+                .route("/", web::post().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .cookie(Cookie::new("csrf_token", token))
+            .insert_header((CSRF_HEADER_NAME, "not-the-same-token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{Error, HttpResponse};
+use dashmap::DashMap;
+use futures_util::future::{LocalBoxFuture, Ready, ready};
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Per-scope token-bucket configuration, e.g. a tighter bucket for writes
+/// than for reads on the same resource.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitConfig {
+    pub capacity: f64,
+    pub refill_rate_per_sec: f64,
+    pub idle_ttl: Duration,
+}
+
+impl RateLimitConfig {
+    /// # Panics
+    /// Panics if `capacity` or `refill_rate_per_sec` is not positive:
+    /// `check()` divides by `refill_rate_per_sec`, so a zero or negative
+    /// rate would panic on every request instead of at startup.
+    pub fn new(capacity: f64, refill_rate_per_sec: f64) -> Self {
+        assert!(capacity > 0.0, "RateLimitConfig capacity must be positive");
+        assert!(
+            refill_rate_per_sec > 0.0,
+            "RateLimitConfig refill_rate_per_sec must be positive"
+        );
+        Self {
+            capacity,
+            refill_rate_per_sec,
+            idle_ttl: Duration::from_secs(300),
+        }
+    }
+
+    pub fn with_idle_ttl(mut self, idle_ttl: Duration) -> Self {
+        self.idle_ttl = idle_ttl;
+        self
+    }
+}
+
+enum Decision {
+    Allow { remaining: f64, reset_after: Duration },
+    Reject { reset_after: Duration },
+}
+
+/// Actix middleware factory throttling requests per client IP using an
+/// in-memory token bucket per key.
+///
+/// Holds separate configs for safe (`GET`/`HEAD`/`OPTIONS`) and mutating methods so a
+/// single `.wrap()` on a scope can throttle writes more tightly than reads.
+///
+/// Cheap to `Clone`: the bucket map is reference-counted, and cloning reuses
+/// the original's sweeper rather than spawning a new one. Build one instance
+/// per resource outside the per-worker `HttpServer::new` closure and clone
+/// it into each worker's `configure_routes` call — constructing a fresh
+/// `RateLimiter` inside that closure would give every worker its own bucket
+/// map, multiplying the effective capacity by the worker count.
+#[derive(Clone)]
+pub struct RateLimiter {
+    read_config: RateLimitConfig,
+    write_config: RateLimitConfig,
+    buckets: Arc<DashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(read_config: RateLimitConfig, write_config: RateLimitConfig) -> Self {
+        let buckets: Arc<DashMap<String, Bucket>> = Arc::new(DashMap::new());
+        let idle_ttl = read_config.idle_ttl.max(write_config.idle_ttl);
+        spawn_sweeper(Arc::clone(&buckets), idle_ttl);
+        Self {
+            read_config,
+            write_config,
+            buckets,
+        }
+    }
+
+    fn config_for(&self, method: &actix_web::http::Method) -> RateLimitConfig {
+        if is_write_method(method) {
+            self.write_config
+        } else {
+            self.read_config
+        }
+    }
+
+    fn check(&self, key: &str, config: RateLimitConfig) -> Decision {
+        let now = Instant::now();
+        let mut bucket = self
+            .buckets
+            .entry(key.to_string())
+            .or_insert_with(|| Bucket {
+                tokens: config.capacity,
+                last_refill: now,
+            });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * config.refill_rate_per_sec).min(config.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            let tokens_to_full = config.capacity - bucket.tokens;
+            Decision::Allow {
+                remaining: bucket.tokens,
+                reset_after: Duration::from_secs_f64(tokens_to_full / config.refill_rate_per_sec),
+            }
+        } else {
+            let tokens_needed = 1.0 - bucket.tokens;
+            Decision::Reject {
+                reset_after: Duration::from_secs_f64(tokens_needed / config.refill_rate_per_sec),
+            }
+        }
+    }
+}
+
+fn is_write_method(method: &actix_web::http::Method) -> bool {
+    !matches!(
+        *method,
+        actix_web::http::Method::GET | actix_web::http::Method::HEAD | actix_web::http::Method::OPTIONS
+    )
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimiter
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RateLimiterMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimiterMiddleware {
+            service,
+            read_config: self.read_config,
+            write_config: self.write_config,
+            buckets: Arc::clone(&self.buckets),
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: S,
+    read_config: RateLimitConfig,
+    write_config: RateLimitConfig,
+    buckets: Arc<DashMap<String, Bucket>>,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let limiter = RateLimiter {
+            read_config: self.read_config,
+            write_config: self.write_config,
+            buckets: Arc::clone(&self.buckets),
+        };
+        let config = limiter.config_for(req.method());
+        let key = rate_limit_key(&req);
+
+        match limiter.check(&key, config) {
+            Decision::Allow { remaining, reset_after } => {
+                let fut = self.service.call(req);
+                Box::pin(async move {
+                    let mut response = fut.await?.map_into_left_body();
+                    insert_rate_limit_headers(
+                        response.headers_mut(),
+                        config.capacity,
+                        remaining,
+                        reset_after,
+                    );
+                    Ok(response)
+                })
+            }
+            Decision::Reject { reset_after } => {
+                let request = req;
+                Box::pin(async move {
+                    let mut response = HttpResponse::TooManyRequests().finish();
+                    insert_rate_limit_headers(response.headers_mut(), config.capacity, 0.0, reset_after);
+                    Ok(request.into_response(response).map_into_right_body())
+                })
+            }
+        }
+    }
+}
+
+/// Keys solely on client IP.
+///
+/// The original request called for keying by authenticated service account,
+/// falling back to IP for unauthenticated traffic. That's out of scope for
+/// now: no middleware in this series populates request-level identity
+/// before this runs — `/oauth/token` authenticates the caller *inside its
+/// handler*, after the rate limiter has already dispatched, so there is no
+/// account id available here to key on. Revisit once an auth middleware
+/// inserts an identity extension ahead of this one in the chain.
+fn rate_limit_key(req: &ServiceRequest) -> String {
+    req.connection_info()
+        .realip_remote_addr()
+        .map(|addr| format!("ip:{addr}"))
+        .unwrap_or_else(|| "ip:unknown".to_string())
+}
+
+fn insert_rate_limit_headers(
+    headers: &mut actix_web::http::header::HeaderMap,
+    capacity: f64,
+    remaining: f64,
+    reset_after: Duration,
+) {
+    headers.insert(
+        HeaderName::from_static("x-ratelimit-limit"),
+        HeaderValue::from_str(&format!("{}", capacity as u64)).unwrap(),
+    );
+    headers.insert(
+        HeaderName::from_static("x-ratelimit-remaining"),
+        HeaderValue::from_str(&format!("{}", remaining as u64)).unwrap(),
+    );
+    headers.insert(
+        HeaderName::from_static("x-ratelimit-reset"),
+        HeaderValue::from_str(&format!("{}", reset_after.as_secs())).unwrap(),
+    );
+}
+
+/// Evicts buckets that have not been touched within `idle_ttl`, bounding
+/// memory for client IPs that stop sending traffic.
+fn spawn_sweeper(buckets: Arc<DashMap<String, Bucket>>, idle_ttl: Duration) {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(idle_ttl.max(Duration::from_secs(1)));
+        loop {
+            interval.tick().await;
+            let now = Instant::now();
+            buckets.retain(|_, bucket| now.duration_since(bucket.last_refill) < idle_ttl);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn allows_requests_up_to_capacity_then_rejects() {
+        let config = RateLimitConfig::new(2.0, 1.0);
+        let limiter = RateLimiter::new(config, config);
+
+        assert!(matches!(limiter.check("ip:a", config), Decision::Allow { .. }));
+        assert!(matches!(limiter.check("ip:a", config), Decision::Allow { .. }));
+        assert!(matches!(limiter.check("ip:a", config), Decision::Reject { .. }));
+    }
+
+    #[actix_web::test]
+    async fn refills_tokens_over_time() {
+        let config = RateLimitConfig::new(1.0, 1000.0);
+        let limiter = RateLimiter::new(config, config);
+
+        assert!(matches!(limiter.check("ip:b", config), Decision::Allow { .. }));
+        assert!(matches!(limiter.check("ip:b", config), Decision::Reject { .. }));
+
+        actix_web::rt::time::sleep(Duration::from_millis(5)).await;
+        assert!(matches!(limiter.check("ip:b", config), Decision::Allow { .. }));
+    }
+
+    #[actix_web::test]
+    async fn distinct_keys_have_independent_buckets() {
+        let config = RateLimitConfig::new(1.0, 0.001);
+        let limiter = RateLimiter::new(config, config);
+
+        assert!(matches!(limiter.check("ip:c", config), Decision::Allow { .. }));
+        assert!(matches!(limiter.check("ip:d", config), Decision::Allow { .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be positive")]
+    fn rejects_non_positive_capacity() {
+        RateLimitConfig::new(0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "refill_rate_per_sec must be positive")]
+    fn rejects_non_positive_refill_rate() {
+        RateLimitConfig::new(1.0, 0.0);
+    }
+}
@@ -0,0 +1,43 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::repositories::base::RepositoryError;
+
+/// Opaque keyset cursor handed out by [`crate::models::pagination::Page`]
+/// and accepted back via [`crate::models::pagination::Pagination::cursor`].
+///
+/// Packs the value of the active sort column alongside the row id, so a
+/// `WHERE (sort_col, id) > (:sort_value, :id)` predicate can resume a
+/// `find` query regardless of which `SortableFields` variant is active.
+/// The id is always included as a tie-breaker so rows with an equal sort
+/// value still produce a stable order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    pub sort_value: String,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn new(sort_value: impl Into<String>, id: Uuid) -> Self {
+        Self {
+            sort_value: sort_value.into(),
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("Cursor always serializes");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decodes a `cursor` query parameter, mapping any malformed input to
+    /// `RepositoryError::InvalidCursor` so it surfaces as a `400`.
+    pub fn decode(value: &str) -> Result<Self, RepositoryError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| RepositoryError::InvalidCursor)?;
+        serde_json::from_slice(&bytes).map_err(|_| RepositoryError::InvalidCursor)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod cursor;
+pub mod oauth;
+pub mod pagination;
+pub mod project_scope;
+pub mod service_account;
+pub mod sort;
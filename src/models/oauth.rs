@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// `POST /oauth/token` request body (RFC 6749 client-credentials grant).
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct TokenRequest {
+    pub grant_type: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub project_id: String,
+    pub scope: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub scope: String,
+}
+
+/// `POST /oauth/introspect` request body (RFC 7662).
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct IntrospectRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct IntrospectResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+}
+
+impl IntrospectResponse {
+    pub fn inactive() -> Self {
+        Self {
+            active: false,
+            scope: None,
+            sub: None,
+            exp: None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Pagination parameters accepted by `list` query strings.
+///
+/// Two modes are supported: classic `limit`/`offset`, kept for backward
+/// compatibility, and an opt-in `cursor` mode that seeks directly to the
+/// last-seen row via a keyset predicate instead of skipping `offset` rows
+/// on every request. When `cursor` is present it takes priority over
+/// `offset`.
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct Pagination {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+impl Pagination {
+    pub const DEFAULT_LIMIT: u32 = 10;
+    pub const MAX_LIMIT: u32 = 200;
+
+    /// Clamped to `[1, MAX_LIMIT]`. A `limit=0` page has no last row to
+    /// derive a resume cursor from, so callers would see `next_cursor` come
+    /// back `None` even when more rows exist; floor it at 1 instead of
+    /// special-casing an empty page downstream.
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(Self::DEFAULT_LIMIT).clamp(1, Self::MAX_LIMIT) as i64
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0) as i64
+    }
+}
+
+/// A page of results plus the cursor to pass back as `?cursor=` to fetch
+/// the next page, or `None` once the result set is exhausted.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::sort::FieldSort;
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema, sqlx::FromRow)]
+pub struct ProjectScopeResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub scope: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct ProjectScopeCreatePayload {
+    pub project_id: String,
+    pub scope: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct ProjectScopeUpdatePayload {
+    pub scope: Option<String>,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectScopeFilter {
+    pub project_id: Option<String>,
+    pub scope: Option<String>,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectScopeSortableFields {
+    Id,
+    ProjectId,
+    Scope,
+    Enabled,
+}
+
+impl ProjectScopeSortableFields {
+    pub fn as_column(&self) -> &'static str {
+        match self {
+            ProjectScopeSortableFields::Id => "id",
+            ProjectScopeSortableFields::ProjectId => "project_id",
+            ProjectScopeSortableFields::Scope => "scope",
+            ProjectScopeSortableFields::Enabled => "enabled",
+        }
+    }
+
+    /// Renders `row`'s value for this field as a cursor's `sort_value`.
+    /// The repository parses it back to the matching column type when it
+    /// builds the keyset predicate for the next page.
+    pub fn cursor_value(&self, row: &ProjectScopeResponse) -> String {
+        match self {
+            ProjectScopeSortableFields::Id => row.id.to_string(),
+            ProjectScopeSortableFields::ProjectId => row.project_id.to_string(),
+            ProjectScopeSortableFields::Scope => row.scope.clone(),
+            ProjectScopeSortableFields::Enabled => row.enabled.to_string(),
+        }
+    }
+}
+
+pub type ProjectScopeSortOrder = FieldSort<ProjectScopeSortableFields>;
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::sort::FieldSort;
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema, sqlx::FromRow)]
+pub struct ServiceAccountResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct ServiceAccountCreatePayload {
+    pub name: String,
+    pub email: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct ServiceAccountUpdatePayload {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct ServiceAccountFilter {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceAccountSortableFields {
+    Id,
+    Name,
+    Email,
+    Enabled,
+}
+
+impl ServiceAccountSortableFields {
+    pub fn as_column(&self) -> &'static str {
+        match self {
+            ServiceAccountSortableFields::Id => "id",
+            ServiceAccountSortableFields::Name => "name",
+            ServiceAccountSortableFields::Email => "email",
+            ServiceAccountSortableFields::Enabled => "enabled",
+        }
+    }
+
+    /// Renders `row`'s value for this field as a cursor's `sort_value`.
+    /// The repository parses it back to the matching column type when it
+    /// builds the keyset predicate for the next page.
+    pub fn cursor_value(&self, row: &ServiceAccountResponse) -> String {
+        match self {
+            ServiceAccountSortableFields::Id => row.id.to_string(),
+            ServiceAccountSortableFields::Name => row.name.clone(),
+            ServiceAccountSortableFields::Email => row.email.clone(),
+            ServiceAccountSortableFields::Enabled => row.enabled.to_string(),
+        }
+    }
+}
+
+pub type ServiceAccountSortOrder = FieldSort<ServiceAccountSortableFields>;
+
+/// Internal row used only to verify client-credential secrets; the hash
+/// never leaves [`crate::repositories::service_account_repository`].
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct ServiceAccountCredentialRow {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub description: String,
+    pub enabled: bool,
+    pub secret_hash: String,
+}
+
+impl From<ServiceAccountCredentialRow> for ServiceAccountResponse {
+    fn from(row: ServiceAccountCredentialRow) -> Self {
+        ServiceAccountResponse {
+            id: row.id,
+            name: row.name,
+            email: row.email,
+            description: row.description,
+            enabled: row.enabled,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// Direction to apply when ordering a `find` query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+/// Pairs a sortable field with the direction to sort it in.
+///
+/// Each entity defines its own `SortableFields` enum and aliases this to e.g.
+/// `ServiceAccountSortOrder = FieldSort<ServiceAccountSortableFields>`.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldSort<F> {
+    pub field: F,
+    pub order: SortOrder,
+}
+
+impl<F> FieldSort<F> {
+    pub fn new(field: F, order: SortOrder) -> Self {
+        Self { field, order }
+    }
+}
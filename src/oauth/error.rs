@@ -0,0 +1,63 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+
+/// RFC 6749 §5.2 error codes returned by `/oauth/token` and `/oauth/introspect`.
+#[derive(Debug)]
+pub enum OAuthError {
+    UnsupportedGrantType,
+    InvalidClient,
+    InvalidScope,
+    ServerError,
+}
+
+impl OAuthError {
+    fn code(&self) -> &'static str {
+        match self {
+            OAuthError::UnsupportedGrantType => "unsupported_grant_type",
+            OAuthError::InvalidClient => "invalid_client",
+            OAuthError::InvalidScope => "invalid_scope",
+            OAuthError::ServerError => "server_error",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            OAuthError::UnsupportedGrantType => "only the client_credentials grant is supported",
+            OAuthError::InvalidClient => "client authentication failed",
+            OAuthError::InvalidScope => "requested scope is unknown or not enabled for this client",
+            OAuthError::ServerError => "the authorization server encountered an unexpected condition",
+        }
+    }
+}
+
+impl std::fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::error::Error for OAuthError {}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct OAuthErrorBody {
+    error: &'static str,
+    error_description: &'static str,
+}
+
+impl actix_web::ResponseError for OAuthError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            OAuthError::UnsupportedGrantType => StatusCode::BAD_REQUEST,
+            OAuthError::InvalidClient => StatusCode::UNAUTHORIZED,
+            OAuthError::InvalidScope => StatusCode::BAD_REQUEST,
+            OAuthError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code()).json(OAuthErrorBody {
+            error: self.code(),
+            error_description: self.description(),
+        })
+    }
+}
@@ -0,0 +1,104 @@
+pub mod error;
+
+use chrono::{Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Claims embedded in access tokens minted by `POST /oauth/token`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenClaims {
+    pub sub: String,
+    pub aud: String,
+    pub scope: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Signing configuration for the authorization server. Construct one
+/// instance at app startup and share it via `web::Data`.
+pub struct OAuthConfig {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    pub ttl: Duration,
+}
+
+impl OAuthConfig {
+    pub fn hs256(secret: &[u8], ttl: Duration) -> Self {
+        Self {
+            algorithm: Algorithm::HS256,
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            ttl,
+        }
+    }
+
+    pub fn rs256(
+        private_key_pem: &[u8],
+        public_key_pem: &[u8],
+        ttl: Duration,
+    ) -> Result<Self, jsonwebtoken::errors::Error> {
+        Ok(Self {
+            algorithm: Algorithm::RS256,
+            encoding_key: EncodingKey::from_rsa_pem(private_key_pem)?,
+            decoding_key: DecodingKey::from_rsa_pem(public_key_pem)?,
+            ttl,
+        })
+    }
+
+    pub fn sign(&self, sub: Uuid, aud: Uuid, scope: &str) -> Result<String, jsonwebtoken::errors::Error> {
+        let now = Utc::now();
+        let claims = TokenClaims {
+            sub: sub.to_string(),
+            aud: aud.to_string(),
+            scope: scope.to_string(),
+            iat: now.timestamp(),
+            exp: (now + self.ttl).timestamp(),
+        };
+        encode(&Header::new(self.algorithm), &claims, &self.encoding_key)
+    }
+
+    pub fn verify(&self, token: &str) -> Result<TokenClaims, jsonwebtoken::errors::Error> {
+        let mut validation = Validation::new(self.algorithm);
+        validation.validate_aud = false;
+        Ok(decode::<TokenClaims>(token, &self.decoding_key, &validation)?.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_accepts_a_token_it_signed() {
+        let config = OAuthConfig::hs256(b"test-secret", Duration::seconds(3600));
+        let sub = Uuid::new_v4();
+        let aud = Uuid::new_v4();
+
+        let token = config.sign(sub, aud, "read write").unwrap();
+        let claims = config.verify(&token).unwrap();
+
+        assert_eq!(claims.sub, sub.to_string());
+        assert_eq!(claims.aud, aud.to_string());
+        assert_eq!(claims.scope, "read write");
+    }
+
+    #[test]
+    fn verify_rejects_an_expired_token() {
+        let config = OAuthConfig::hs256(b"test-secret", Duration::seconds(-1));
+        let token = config.sign(Uuid::new_v4(), Uuid::new_v4(), "read").unwrap();
+
+        assert!(config.verify(&token).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_a_token_signed_with_a_different_secret() {
+        let token = OAuthConfig::hs256(b"secret-a", Duration::seconds(3600))
+            .sign(Uuid::new_v4(), Uuid::new_v4(), "read")
+            .unwrap();
+
+        let other = OAuthConfig::hs256(b"secret-b", Duration::seconds(3600));
+        assert!(other.verify(&token).is_err());
+    }
+}
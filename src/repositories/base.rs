@@ -0,0 +1,136 @@
+use actix_web::http::StatusCode;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::pagination::Pagination;
+use crate::models::sort::FieldSort;
+
+/// Failure modes shared by every repository, in place of ad-hoc string
+/// messages that handlers used to `match` on via `error.to_string()`.
+#[derive(Debug)]
+pub enum RepositoryError {
+    /// The row addressed by id/key does not exist.
+    NotFound { entity: &'static str },
+    /// A referenced parent row (foreign key) does not exist.
+    ForeignKeyMissing { entity: &'static str },
+    /// A unique constraint would be violated by this write.
+    DuplicateUnique { field: &'static str },
+    /// An update payload left every field unset.
+    NoChanges,
+    /// A `cursor` query parameter failed to decode into a valid keyset.
+    InvalidCursor,
+    /// Anything else the database reported.
+    Database(sqlx::Error),
+}
+
+impl std::fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryError::NotFound { entity } => write!(f, "{entity} not found"),
+            RepositoryError::ForeignKeyMissing { entity } => write!(f, "{entity} not found"),
+            RepositoryError::DuplicateUnique { field } => write!(f, "{field} already exists"),
+            RepositoryError::NoChanges => write!(f, "No changes to update"),
+            RepositoryError::InvalidCursor => write!(f, "Invalid pagination cursor"),
+            RepositoryError::Database(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryError::Database(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<sqlx::Error> for RepositoryError {
+    fn from(error: sqlx::Error) -> Self {
+        RepositoryError::Database(error)
+    }
+}
+
+impl RepositoryError {
+    /// Short name of the active variant, used as a `tracing` span field so
+    /// failures are greppable without formatting the full `Display` text.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            RepositoryError::NotFound { .. } => "NotFound",
+            RepositoryError::ForeignKeyMissing { .. } => "ForeignKeyMissing",
+            RepositoryError::DuplicateUnique { .. } => "DuplicateUnique",
+            RepositoryError::NoChanges => "NoChanges",
+            RepositoryError::InvalidCursor => "InvalidCursor",
+            RepositoryError::Database(_) => "Database",
+        }
+    }
+}
+
+/// Records a single-row write's outcome onto the current `tracing` span:
+/// the affected row's id and a `rows` count of `1` on success, or the
+/// failing variant's name on error. Used by both repositories' `create`
+/// and by `ServiceAccountRepository::update`, whose `#[tracing::instrument]`
+/// declares matching `id`/`rows`/`error` fields.
+pub fn record_outcome<T>(result: &Result<T, RepositoryError>, id_of: impl FnOnce(&T) -> Uuid) {
+    let span = tracing::Span::current();
+    match result {
+        Ok(value) => {
+            span.record("id", tracing::field::display(id_of(value)));
+            span.record("rows", 1u64);
+        }
+        Err(error) => {
+            span.record("error", error.variant_name());
+        }
+    }
+}
+
+impl actix_web::ResponseError for RepositoryError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RepositoryError::NotFound { .. } => StatusCode::NOT_FOUND,
+            RepositoryError::ForeignKeyMissing { .. } => StatusCode::BAD_REQUEST,
+            RepositoryError::DuplicateUnique { .. } => StatusCode::CONFLICT,
+            RepositoryError::NoChanges => StatusCode::BAD_REQUEST,
+            RepositoryError::InvalidCursor => StatusCode::BAD_REQUEST,
+            RepositoryError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code()).json(self.to_string())
+    }
+}
+
+/// Common CRUD + search surface implemented by every entity repository.
+#[async_trait]
+pub trait Repository {
+    type Model;
+    type CreatePayload;
+    type UpdatePayload;
+    type Filter;
+    type SortableFields;
+
+    async fn create(&self, payload: Self::CreatePayload) -> Result<Self::Model, RepositoryError>;
+
+    async fn read(&self, id: Uuid) -> Result<Option<Self::Model>, RepositoryError>;
+
+    async fn update(
+        &self,
+        id: Uuid,
+        payload: Self::UpdatePayload,
+    ) -> Result<Self::Model, RepositoryError>;
+
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
+
+    /// Returns a page of matches plus the cursor to resume from, or `None`
+    /// once the result set is exhausted. The cursor encodes the last row
+    /// returned, in offset mode as well as in cursor mode, so callers may
+    /// switch from offset to cursor mode on a later request and resume
+    /// right after that row.
+    async fn find(
+        &self,
+        filter: Self::Filter,
+        sort: Option<Vec<FieldSort<Self::SortableFields>>>,
+        pagination: Option<Pagination>,
+    ) -> Result<(Vec<Self::Model>, Option<String>), RepositoryError>;
+}
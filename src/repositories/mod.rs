@@ -0,0 +1,3 @@
+pub mod base;
+pub mod project_scope_repository;
+pub mod service_account_repository;
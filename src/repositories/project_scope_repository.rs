@@ -0,0 +1,319 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::cursor::Cursor;
+use crate::models::pagination::Pagination;
+use crate::models::project_scope::{
+    ProjectScopeCreatePayload, ProjectScopeFilter, ProjectScopeResponse, ProjectScopeSortOrder,
+    ProjectScopeSortableFields, ProjectScopeUpdatePayload,
+};
+use crate::models::sort::{FieldSort, SortOrder};
+use crate::repositories::base::{Repository, RepositoryError, record_outcome};
+
+pub struct ProjectScopeRepository {
+    pool: Arc<PgPool>,
+}
+
+impl ProjectScopeRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+
+    /// Returns the enabled scopes of `project_id` that `account_id` has
+    /// actually been granted, via the `service_account_scopes` join table an
+    /// admin populates when provisioning a client.
+    ///
+    /// This is the authorization check `/oauth/token` relies on: a project's
+    /// enabled scopes alone say nothing about which service accounts may
+    /// request them, so callers must intersect requested scopes against
+    /// this set rather than against every enabled `ProjectScopeResponse` for
+    /// the project.
+    pub async fn authorized_for_account(
+        &self,
+        account_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectScopeResponse>, RepositoryError> {
+        sqlx::query_as::<_, ProjectScopeResponse>(
+            "SELECT ps.id, ps.project_id, ps.scope, ps.description, ps.enabled
+             FROM project_scopes ps
+             JOIN service_account_scopes sas ON sas.project_scope_id = ps.id
+             WHERE sas.service_account_id = $1 AND ps.project_id = $2 AND ps.enabled = true",
+        )
+        .bind(account_id)
+        .bind(project_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(RepositoryError::Database)
+    }
+}
+
+#[async_trait]
+impl Repository for ProjectScopeRepository {
+    type Model = ProjectScopeResponse;
+    type CreatePayload = ProjectScopeCreatePayload;
+    type UpdatePayload = ProjectScopeUpdatePayload;
+    type Filter = ProjectScopeFilter;
+    type SortableFields = ProjectScopeSortableFields;
+
+    #[tracing::instrument(
+        skip(self, payload),
+        fields(project_id = %payload.project_id, scope = %payload.scope, id = tracing::field::Empty, rows = tracing::field::Empty, error = tracing::field::Empty),
+    )]
+    async fn create(&self, payload: ProjectScopeCreatePayload) -> Result<ProjectScopeResponse, RepositoryError> {
+        let result = self.create_inner(payload).await;
+        record_outcome(&result, |scope| scope.id);
+        result
+    }
+
+    #[tracing::instrument(skip(self), fields(id = %id, project_id = tracing::field::Empty, scope = tracing::field::Empty, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn read(&self, id: Uuid) -> Result<Option<ProjectScopeResponse>, RepositoryError> {
+        let result = sqlx::query_as::<_, ProjectScopeResponse>(
+            "SELECT id, project_id, scope, description, enabled FROM project_scopes WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(RepositoryError::Database);
+
+        let span = tracing::Span::current();
+        match &result {
+            Ok(Some(scope)) => {
+                span.record("project_id", tracing::field::display(scope.project_id));
+                span.record("scope", scope.scope.as_str());
+                span.record("rows", 1u64);
+            }
+            Ok(None) => {
+                span.record("rows", 0u64);
+            }
+            Err(error) => {
+                span.record("error", error.variant_name());
+            }
+        }
+        result
+    }
+
+    #[tracing::instrument(skip(self, payload), fields(id = %id, project_id = tracing::field::Empty, scope = tracing::field::Empty, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn update(
+        &self,
+        id: Uuid,
+        payload: ProjectScopeUpdatePayload,
+    ) -> Result<ProjectScopeResponse, RepositoryError> {
+        if payload.scope.is_none() && payload.description.is_none() && payload.enabled.is_none() {
+            tracing::Span::current().record("error", RepositoryError::NoChanges.variant_name());
+            return Err(RepositoryError::NoChanges);
+        }
+
+        let result = sqlx::query_as::<_, ProjectScopeResponse>(
+            "UPDATE project_scopes
+             SET scope = COALESCE($2, scope),
+                 description = COALESCE($3, description),
+                 enabled = COALESCE($4, enabled)
+             WHERE id = $1
+             RETURNING id, project_id, scope, description, enabled",
+        )
+        .bind(id)
+        .bind(&payload.scope)
+        .bind(&payload.description)
+        .bind(payload.enabled)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|error| match error.as_database_error() {
+            Some(db_error) if db_error.is_unique_violation() => RepositoryError::DuplicateUnique {
+                field: "Project Id, scope combination",
+            },
+            _ => RepositoryError::Database(error),
+        })
+        .and_then(|row| {
+            row.ok_or(RepositoryError::NotFound {
+                entity: "Project scope",
+            })
+        });
+
+        let span = tracing::Span::current();
+        match &result {
+            Ok(scope) => {
+                span.record("project_id", tracing::field::display(scope.project_id));
+                span.record("scope", scope.scope.as_str());
+                span.record("rows", 1u64);
+            }
+            Err(error) => {
+                span.record("error", error.variant_name());
+            }
+        }
+        result
+    }
+
+    #[tracing::instrument(skip(self), fields(id = %id, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM project_scopes WHERE id = $1")
+            .bind(id)
+            .execute(&*self.pool)
+            .await
+            .map_err(RepositoryError::Database)
+            .and_then(|result| {
+                if result.rows_affected() == 0 {
+                    Err(RepositoryError::NotFound {
+                        entity: "Project scope",
+                    })
+                } else {
+                    Ok(true)
+                }
+            });
+
+        let span = tracing::Span::current();
+        match &result {
+            Ok(deleted) => span.record("rows", *deleted as u64),
+            Err(error) => span.record("error", error.variant_name()),
+        };
+        result
+    }
+
+    #[tracing::instrument(skip(self, filter, sort), fields(project_id = tracing::field::Empty, scope = tracing::field::Empty, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn find(
+        &self,
+        filter: ProjectScopeFilter,
+        sort: Option<Vec<FieldSort<ProjectScopeSortableFields>>>,
+        pagination: Option<Pagination>,
+    ) -> Result<(Vec<ProjectScopeResponse>, Option<String>), RepositoryError> {
+        let span = tracing::Span::current();
+        if let Some(project_id) = &filter.project_id {
+            span.record("project_id", project_id.as_str());
+        }
+        if let Some(scope) = &filter.scope {
+            span.record("scope", scope.as_str());
+        }
+
+        let result = self.find_query(filter, sort, pagination).await;
+        match &result {
+            Ok((rows, _)) => {
+                span.record("rows", rows.len() as u64);
+            }
+            Err(error) => {
+                span.record("error", error.variant_name());
+            }
+        }
+        result
+    }
+}
+
+impl ProjectScopeRepository {
+    async fn create_inner(&self, payload: ProjectScopeCreatePayload) -> Result<ProjectScopeResponse, RepositoryError> {
+        let project_id = Uuid::parse_str(&payload.project_id)
+            .map_err(|_| RepositoryError::ForeignKeyMissing { entity: "Project" })?;
+
+        sqlx::query_as::<_, ProjectScopeResponse>(
+            "INSERT INTO project_scopes (project_id, scope, description, enabled)
+             VALUES ($1, $2, $3, $4)
+             RETURNING id, project_id, scope, description, enabled",
+        )
+        .bind(project_id)
+        .bind(&payload.scope)
+        .bind(&payload.description)
+        .bind(payload.enabled)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|error| match error.as_database_error() {
+            Some(db_error) if db_error.is_foreign_key_violation() => {
+                RepositoryError::ForeignKeyMissing { entity: "Project" }
+            }
+            Some(db_error) if db_error.is_unique_violation() => RepositoryError::DuplicateUnique {
+                field: "Project Id, scope combination",
+            },
+            _ => RepositoryError::Database(error),
+        })
+    }
+
+    async fn find_query(
+        &self,
+        filter: ProjectScopeFilter,
+        sort: Option<Vec<FieldSort<ProjectScopeSortableFields>>>,
+        pagination: Option<Pagination>,
+    ) -> Result<(Vec<ProjectScopeResponse>, Option<String>), RepositoryError> {
+        let mut query = sqlx::QueryBuilder::new(
+            "SELECT id, project_id, scope, description, enabled FROM project_scopes WHERE 1 = 1",
+        );
+
+        if let Some(project_id) = filter.project_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()) {
+            query.push(" AND project_id = ").push_bind(project_id);
+        }
+        if let Some(scope) = &filter.scope {
+            query.push(" AND scope = ").push_bind(scope);
+        }
+        if let Some(description) = &filter.description {
+            query.push(" AND description ILIKE ").push_bind(format!("%{description}%"));
+        }
+        if let Some(enabled) = filter.enabled {
+            query.push(" AND enabled = ").push_bind(enabled);
+        }
+
+        let field_sort = sort
+            .as_ref()
+            .and_then(|sort| sort.first())
+            .copied()
+            .unwrap_or_else(|| ProjectScopeSortOrder::new(ProjectScopeSortableFields::Id, SortOrder::Asc));
+
+        let pagination = pagination.unwrap_or_default();
+
+        if let Some(cursor) = pagination.cursor.as_deref() {
+            let cursor = Cursor::decode(cursor)?;
+            let comparator = match field_sort.order {
+                SortOrder::Asc => ">",
+                SortOrder::Desc => "<",
+            };
+            query
+                .push(" AND (")
+                .push(field_sort.field.as_column())
+                .push(", id) ")
+                .push(comparator)
+                .push(" (");
+            match field_sort.field {
+                ProjectScopeSortableFields::Id | ProjectScopeSortableFields::ProjectId => {
+                    let value = Uuid::parse_str(&cursor.sort_value)
+                        .map_err(|_| RepositoryError::InvalidCursor)?;
+                    query.push_bind(value);
+                }
+                ProjectScopeSortableFields::Enabled => {
+                    let value: bool = cursor
+                        .sort_value
+                        .parse()
+                        .map_err(|_| RepositoryError::InvalidCursor)?;
+                    query.push_bind(value);
+                }
+                ProjectScopeSortableFields::Scope => {
+                    query.push_bind(cursor.sort_value.clone());
+                }
+            }
+            query.push(", ").push_bind(cursor.id).push(")");
+        }
+
+        query.push(" ORDER BY ").push(field_sort.field.as_column()).push(' ').push(field_sort.order.as_sql());
+        if field_sort.field.as_column() != "id" {
+            query.push(", id ").push(field_sort.order.as_sql());
+        }
+
+        let limit = pagination.limit();
+        query.push(" LIMIT ").push_bind(limit + 1);
+        if pagination.cursor.is_none() {
+            query.push(" OFFSET ").push_bind(pagination.offset());
+        }
+
+        let mut rows = query
+            .build_query_as::<ProjectScopeResponse>()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(RepositoryError::Database)?;
+
+        let next_cursor = if rows.len() > limit as usize {
+            rows.truncate(limit as usize);
+            rows.last()
+                .map(|row| Cursor::new(field_sort.field.cursor_value(row), row.id).encode())
+        } else {
+            None
+        };
+
+        Ok((rows, next_cursor))
+    }
+}
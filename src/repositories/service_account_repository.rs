@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::cursor::Cursor;
+use crate::models::pagination::Pagination;
+use crate::models::service_account::{
+    ServiceAccountCreatePayload, ServiceAccountCredentialRow, ServiceAccountFilter,
+    ServiceAccountResponse, ServiceAccountSortOrder, ServiceAccountSortableFields,
+    ServiceAccountUpdatePayload,
+};
+use crate::models::sort::{FieldSort, SortOrder};
+use crate::repositories::base::{Repository, RepositoryError, record_outcome};
+
+pub struct ServiceAccountRepository {
+    pool: Arc<PgPool>,
+}
+
+impl ServiceAccountRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+
+    /// Verifies an OAuth2 client-credentials pair, returning the account iff
+    /// it is enabled and `secret` matches the stored hash.
+    pub async fn verify_secret(
+        &self,
+        id: Uuid,
+        secret: &str,
+    ) -> Result<ServiceAccountResponse, RepositoryError> {
+        let row = sqlx::query_as::<_, ServiceAccountCredentialRow>(
+            "SELECT id, name, email, description, enabled, secret_hash
+             FROM service_accounts
+             WHERE id = $1 AND enabled = true",
+        )
+        .bind(id)
+        .fetch_optional(&*self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound {
+            entity: "Service account",
+        })?;
+
+        let matches = argon2::verify_encoded(&row.secret_hash, secret.as_bytes()).unwrap_or(false);
+        if !matches {
+            return Err(RepositoryError::NotFound {
+                entity: "Service account",
+            });
+        }
+
+        Ok(row.into())
+    }
+}
+
+#[async_trait]
+impl Repository for ServiceAccountRepository {
+    type Model = ServiceAccountResponse;
+    type CreatePayload = ServiceAccountCreatePayload;
+    type UpdatePayload = ServiceAccountUpdatePayload;
+    type Filter = ServiceAccountFilter;
+    type SortableFields = ServiceAccountSortableFields;
+
+    #[tracing::instrument(skip(self), fields(id = tracing::field::Empty, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn create(
+        &self,
+        payload: ServiceAccountCreatePayload,
+    ) -> Result<ServiceAccountResponse, RepositoryError> {
+        let result = sqlx::query_as::<_, ServiceAccountResponse>(
+            "INSERT INTO service_accounts (name, email, description, enabled)
+             VALUES ($1, $2, $3, $4)
+             RETURNING id, name, email, description, enabled",
+        )
+        .bind(&payload.name)
+        .bind(&payload.email)
+        .bind(&payload.description)
+        .bind(payload.enabled)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|error| map_write_error(error, &payload.name, &payload.email));
+
+        record_outcome(&result, |account| account.id);
+        result
+    }
+
+    #[tracing::instrument(skip(self), fields(id = %id, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn read(&self, id: Uuid) -> Result<Option<ServiceAccountResponse>, RepositoryError> {
+        let result = sqlx::query_as::<_, ServiceAccountResponse>(
+            "SELECT id, name, email, description, enabled FROM service_accounts WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(RepositoryError::Database);
+
+        tracing::Span::current().record("rows", result.as_ref().map(|row| row.is_some() as u64).unwrap_or(0));
+        if let Err(error) = &result {
+            tracing::Span::current().record("error", error.variant_name());
+        }
+        result
+    }
+
+    #[tracing::instrument(skip(self), fields(id = %id, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn update(
+        &self,
+        id: Uuid,
+        payload: ServiceAccountUpdatePayload,
+    ) -> Result<ServiceAccountResponse, RepositoryError> {
+        if payload.name.is_none()
+            && payload.email.is_none()
+            && payload.description.is_none()
+            && payload.enabled.is_none()
+        {
+            tracing::Span::current().record("error", RepositoryError::NoChanges.variant_name());
+            return Err(RepositoryError::NoChanges);
+        }
+
+        let name = payload.name.clone().unwrap_or_default();
+        let email = payload.email.clone().unwrap_or_default();
+
+        let result = sqlx::query_as::<_, ServiceAccountResponse>(
+            "UPDATE service_accounts
+             SET name = COALESCE($2, name),
+                 email = COALESCE($3, email),
+                 description = COALESCE($4, description),
+                 enabled = COALESCE($5, enabled)
+             WHERE id = $1
+             RETURNING id, name, email, description, enabled",
+        )
+        .bind(id)
+        .bind(&payload.name)
+        .bind(&payload.email)
+        .bind(&payload.description)
+        .bind(payload.enabled)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|error| map_write_error(error, &name, &email))
+        .and_then(|row| {
+            row.ok_or(RepositoryError::NotFound {
+                entity: "Service account",
+            })
+        });
+
+        record_outcome(&result, |account| account.id);
+        result
+    }
+
+    #[tracing::instrument(skip(self), fields(id = %id, rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM service_accounts WHERE id = $1")
+            .bind(id)
+            .execute(&*self.pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::Database);
+
+        tracing::Span::current().record("rows", result.as_ref().map(|deleted| *deleted as u64).unwrap_or(0));
+        if let Err(error) = &result {
+            tracing::Span::current().record("error", error.variant_name());
+        }
+        result
+    }
+
+    #[tracing::instrument(skip(self, filter, sort), fields(rows = tracing::field::Empty, error = tracing::field::Empty))]
+    async fn find(
+        &self,
+        filter: ServiceAccountFilter,
+        sort: Option<Vec<FieldSort<ServiceAccountSortableFields>>>,
+        pagination: Option<Pagination>,
+    ) -> Result<(Vec<ServiceAccountResponse>, Option<String>), RepositoryError> {
+        let result = self.find_query(filter, sort, pagination).await;
+        match &result {
+            Ok((rows, _)) => {
+                tracing::Span::current().record("rows", rows.len() as u64);
+            }
+            Err(error) => {
+                tracing::Span::current().record("error", error.variant_name());
+            }
+        }
+        result
+    }
+}
+
+impl ServiceAccountRepository {
+    async fn find_query(
+        &self,
+        filter: ServiceAccountFilter,
+        sort: Option<Vec<FieldSort<ServiceAccountSortableFields>>>,
+        pagination: Option<Pagination>,
+    ) -> Result<(Vec<ServiceAccountResponse>, Option<String>), RepositoryError> {
+        let mut query = sqlx::QueryBuilder::new(
+            "SELECT id, name, email, description, enabled FROM service_accounts WHERE 1 = 1",
+        );
+
+        if let Some(name) = &filter.name {
+            query.push(" AND name ILIKE ").push_bind(format!("%{name}%"));
+        }
+        if let Some(description) = &filter.description {
+            query.push(" AND description ILIKE ").push_bind(format!("%{description}%"));
+        }
+        if let Some(enabled) = filter.enabled {
+            query.push(" AND enabled = ").push_bind(enabled);
+        }
+
+        let field_sort = sort
+            .as_ref()
+            .and_then(|sort| sort.first())
+            .copied()
+            .unwrap_or_else(|| ServiceAccountSortOrder::new(ServiceAccountSortableFields::Id, SortOrder::Asc));
+
+        let pagination = pagination.unwrap_or_default();
+
+        if let Some(cursor) = pagination.cursor.as_deref() {
+            let cursor = Cursor::decode(cursor)?;
+            let comparator = match field_sort.order {
+                SortOrder::Asc => ">",
+                SortOrder::Desc => "<",
+            };
+            query
+                .push(" AND (")
+                .push(field_sort.field.as_column())
+                .push(", id) ")
+                .push(comparator)
+                .push(" (");
+            match field_sort.field {
+                ServiceAccountSortableFields::Id => {
+                    let value = Uuid::parse_str(&cursor.sort_value)
+                        .map_err(|_| RepositoryError::InvalidCursor)?;
+                    query.push_bind(value);
+                }
+                ServiceAccountSortableFields::Enabled => {
+                    let value: bool = cursor
+                        .sort_value
+                        .parse()
+                        .map_err(|_| RepositoryError::InvalidCursor)?;
+                    query.push_bind(value);
+                }
+                ServiceAccountSortableFields::Name | ServiceAccountSortableFields::Email => {
+                    query.push_bind(cursor.sort_value.clone());
+                }
+            }
+            query.push(", ").push_bind(cursor.id).push(")");
+        }
+
+        query.push(" ORDER BY ").push(field_sort.field.as_column()).push(' ').push(field_sort.order.as_sql());
+        if field_sort.field.as_column() != "id" {
+            query.push(", id ").push(field_sort.order.as_sql());
+        }
+
+        let limit = pagination.limit();
+        query.push(" LIMIT ").push_bind(limit + 1);
+        if pagination.cursor.is_none() {
+            query.push(" OFFSET ").push_bind(pagination.offset());
+        }
+
+        let mut rows = query
+            .build_query_as::<ServiceAccountResponse>()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(RepositoryError::Database)?;
+
+        let next_cursor = if rows.len() > limit as usize {
+            rows.truncate(limit as usize);
+            rows.last()
+                .map(|row| Cursor::new(field_sort.field.cursor_value(row), row.id).encode())
+        } else {
+            None
+        };
+
+        Ok((rows, next_cursor))
+    }
+}
+
+/// Maps a unique-constraint violation on `name` or `email` to its matching
+/// `RepositoryError`. A violation whose constraint name mentions neither
+/// column is attributed to `name` if `name` is non-empty, else to `email`
+/// if that is non-empty; any other error maps to `RepositoryError::Database`.
+fn map_write_error(error: sqlx::Error, name: &str, email: &str) -> RepositoryError {
+    let Some(db_error) = error.as_database_error() else {
+        return RepositoryError::Database(error);
+    };
+    if !db_error.is_unique_violation() {
+        return RepositoryError::Database(error);
+    }
+    match db_error.constraint() {
+        Some(constraint) if constraint.contains("email") => RepositoryError::DuplicateUnique {
+            field: "Service account email",
+        },
+        Some(constraint) if constraint.contains("name") => RepositoryError::DuplicateUnique {
+            field: "Service account name",
+        },
+        _ if !name.is_empty() => RepositoryError::DuplicateUnique {
+            field: "Service account name",
+        },
+        _ if !email.is_empty() => RepositoryError::DuplicateUnique {
+            field: "Service account email",
+        },
+        _ => RepositoryError::Database(error),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod oauth_route;
+pub mod project_scope_route;
+pub mod service_account_route;
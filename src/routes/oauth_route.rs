@@ -0,0 +1,165 @@
+use crate::middleware::ratelimit::RateLimiter;
+use crate::models::oauth::{IntrospectRequest, IntrospectResponse, TokenRequest, TokenResponse};
+use crate::models::project_scope::ProjectScopeResponse;
+use crate::oauth::OAuthConfig;
+use crate::oauth::error::{OAuthError, OAuthErrorBody};
+use crate::repositories::project_scope_repository::ProjectScopeRepository;
+use crate::repositories::service_account_repository::ServiceAccountRepository;
+use actix_web::{HttpResponse, web};
+use tracing_actix_web::TracingLogger;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/oauth/token",
+    tag = "OAuth2",
+    request_body(content = TokenRequest, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Access token issued", body = TokenResponse),
+        (status = 400, description = "Unsupported grant type or scope", body = inline(OAuthErrorBody)),
+        (status = 401, description = "Client authentication failed", body = inline(OAuthErrorBody)),
+    ),
+)]
+pub async fn token(
+    accounts: web::Data<ServiceAccountRepository>,
+    scopes: web::Data<ProjectScopeRepository>,
+    oauth_config: web::Data<OAuthConfig>,
+    payload: web::Form<TokenRequest>,
+) -> Result<HttpResponse, OAuthError> {
+    if payload.grant_type != "client_credentials" {
+        return Err(OAuthError::UnsupportedGrantType);
+    }
+
+    let client_id = Uuid::parse_str(&payload.client_id).map_err(|_| OAuthError::InvalidClient)?;
+    let account = accounts
+        .verify_secret(client_id, &payload.client_secret)
+        .await
+        .map_err(|_| OAuthError::InvalidClient)?;
+
+    let project_id = Uuid::parse_str(&payload.project_id).map_err(|_| OAuthError::InvalidScope)?;
+
+    let authorized_scopes = scopes
+        .authorized_for_account(account.id, project_id)
+        .await
+        .map_err(|_| OAuthError::ServerError)?;
+
+    let granted = grant_scopes(payload.scope.as_deref(), &authorized_scopes);
+    if granted.is_empty() {
+        return Err(OAuthError::InvalidScope);
+    }
+
+    let scope = granted.join(" ");
+    let access_token = oauth_config
+        .sign(account.id, project_id, &scope)
+        .map_err(|_| OAuthError::ServerError)?;
+
+    Ok(HttpResponse::Ok().json(TokenResponse {
+        access_token,
+        token_type: "Bearer".to_string(),
+        expires_in: oauth_config.ttl.num_seconds(),
+        scope,
+    }))
+}
+
+/// Intersects the requested scopes (or, if none were requested, every
+/// authorized scope) against `authorized`, returning only what the caller
+/// may actually be granted.
+fn grant_scopes<'a>(requested_scope: Option<&'a str>, authorized: &'a [ProjectScopeResponse]) -> Vec<&'a str> {
+    let requested: Vec<&str> = match requested_scope {
+        Some(scope) => scope.split_whitespace().collect(),
+        None => authorized.iter().map(|scope| scope.scope.as_str()).collect(),
+    };
+
+    requested
+        .into_iter()
+        .filter(|requested_scope| authorized.iter().any(|scope| scope.scope == *requested_scope))
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/oauth/introspect",
+    tag = "OAuth2",
+    request_body(content = IntrospectRequest, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Token introspection result", body = IntrospectResponse),
+    ),
+)]
+pub async fn introspect(
+    oauth_config: web::Data<OAuthConfig>,
+    payload: web::Form<IntrospectRequest>,
+) -> Result<HttpResponse, OAuthError> {
+    let response = match oauth_config.verify(&payload.token) {
+        Ok(claims) => IntrospectResponse {
+            active: true,
+            scope: Some(claims.scope),
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+        },
+        Err(_) => IntrospectResponse::inactive(),
+    };
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// `rate_limiter` must be built once by the caller (e.g. alongside the repo's
+/// other shared `web::Data`) and passed in here on every worker's
+/// `configure_routes` call, so all workers throttle against the same bucket
+/// map instead of each getting its own.
+///
+/// Both routes under this scope are unauthenticated until the handler runs
+/// (`/token` verifies a client secret; `/introspect` takes a bare token), so
+/// this is the only thing standing between either one and brute-force or
+/// CPU-exhaustion attempts against `verify_secret`'s argon2 hashing — wrap
+/// the scope with a dedicated, tighter `RateLimiter` rather than reusing the
+/// CRUD routes' config.
+pub fn configure_routes(config: &mut actix_web::web::ServiceConfig, rate_limiter: RateLimiter) {
+    config.service(
+        web::scope("/oauth")
+            .wrap(rate_limiter)
+            .wrap(TracingLogger::default())
+            .route("/token", web::post().to(token))
+            .route("/introspect", web::post().to(introspect)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope(value: &str) -> ProjectScopeResponse {
+        ProjectScopeResponse {
+            id: Uuid::new_v4(),
+            project_id: Uuid::new_v4(),
+            scope: value.to_string(),
+            description: String::new(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn grants_every_authorized_scope_when_none_is_requested() {
+        let authorized = vec![scope("read"), scope("write")];
+
+        let granted = grant_scopes(None, &authorized);
+
+        assert_eq!(granted, vec!["read", "write"]);
+    }
+
+    #[test]
+    fn grants_only_the_authorized_subset_of_requested_scopes() {
+        let authorized = vec![scope("read")];
+
+        let granted = grant_scopes(Some("read write admin"), &authorized);
+
+        assert_eq!(granted, vec!["read"]);
+    }
+
+    #[test]
+    fn grants_nothing_when_no_requested_scope_is_authorized() {
+        let authorized = vec![scope("read")];
+
+        let granted = grant_scopes(Some("admin"), &authorized);
+
+        assert!(granted.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::middleware::csrf::CsrfConfig;
+use crate::middleware::ratelimit::RateLimiter;
+use crate::models::pagination::{Page, Pagination};
+use crate::models::project_scope::{
+    ProjectScopeCreatePayload, ProjectScopeFilter, ProjectScopeResponse, ProjectScopeSortOrder,
+    ProjectScopeSortableFields, ProjectScopeUpdatePayload,
+};
+use crate::models::sort::SortOrder;
+use crate::repositories::base::{Repository, RepositoryError};
+use crate::repositories::project_scope_repository::ProjectScopeRepository;
+use actix_web::{HttpResponse, web};
+use tracing_actix_web::TracingLogger;
+
+#[utoipa::path(
+    post,
+    path = "/project-scopes",
+    tag = "Project Scopes",
+    request_body = ProjectScopeCreatePayload,
+    responses(
+        (status = 201, description = "Project scope created", body = ProjectScopeResponse),
+        (status = 400, description = "Project not found", body = String),
+        (status = 409, description = "Project Id, scope combination already exists", body = String),
+    ),
+)]
+pub async fn post(
+    repository: web::Data<ProjectScopeRepository>,
+    payload: web::Json<ProjectScopeCreatePayload>,
+) -> Result<HttpResponse, RepositoryError> {
+    let project_scope = repository.create(payload.into_inner()).await?;
+    Ok(HttpResponse::Created().json(project_scope))
+}
+
+#[utoipa::path(
+    get,
+    path = "/project-scopes/{id}",
+    tag = "Project Scopes",
+    responses(
+        (status = 200, description = "Project scope found", body = ProjectScopeResponse),
+        (status = 404, description = "Project scope not found", body = String),
+    ),
+    params(
+        ("id" = String<uuid::Uuid>, Path, description = "Project Scope ID"),
+    ),
+)]
+pub async fn get(
+    repository: web::Data<ProjectScopeRepository>,
+    id: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, RepositoryError> {
+    let project_scope = repository.read(id.into_inner()).await?.ok_or(RepositoryError::NotFound {
+        entity: "Project scope",
+    })?;
+    Ok(HttpResponse::Ok().json(project_scope))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/project-scopes/{id}",
+    tag = "Project Scopes",
+    responses(
+        (status = 200, description = "Project scope updated", body = ProjectScopeResponse),
+        (status = 400, description = "No changes to update", body = String),
+        (status = 404, description = "Project scope not found", body = String),
+        (status = 409, description = "Project Id, scope combination already exists", body = String),
+    ),
+    params(
+        ("id" = String<uuid::Uuid>, Path, description = "Project Scope ID"),
+    ),
+)]
+pub async fn patch(
+    repository: web::Data<ProjectScopeRepository>,
+    id: web::Path<uuid::Uuid>,
+    payload: web::Json<ProjectScopeUpdatePayload>,
+) -> Result<HttpResponse, RepositoryError> {
+    let project_scope = repository.update(id.into_inner(), payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(project_scope))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/project-scopes/{id}",
+    tag = "Project Scopes",
+    responses(
+        (status = 204, description = "Project scope deleted", body = ()),
+        (status = 404, description = "Project scope not found", body = String),
+    ),
+    params(
+        ("id" = String<uuid::Uuid>, Path, description = "Project Scope ID"),
+    )
+)]
+pub async fn delete(
+    repository: web::Data<ProjectScopeRepository>,
+    id: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, RepositoryError> {
+    repository.delete(id.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "/project-scopes",
+    tag = "Project Scopes",
+    responses(
+        (status = 200, description = "Project scopes found", body = Page<ProjectScopeResponse>),
+        (status = 400, description = "Invalid pagination cursor", body = String),
+    ),
+    params(
+        ("project_id" = Option<String>, Query, description = "Filter project scopes by project id"),
+        ("scope" = Option<String>, Query, description = "Filter project scopes by scope"),
+        ("description" = Option<String>, Query, description = "Filter project scopes by description"),
+        ("enabled" = Option<bool>, Query, description = "Filter project scopes by enabled"),
+        ("offset" = Option<u32>, Query, description = "Offset for pagination (ignored when cursor is set)"),
+        ("limit" = Option<u32>, Query, description = "Number of items per page"),
+        ("cursor" = Option<String>, Query, description = "Opaque cursor returned as `next_cursor` by a previous call; resumes keyset pagination instead of using offset"),
+    )
+)]
+pub async fn list(
+    repository: web::Data<ProjectScopeRepository>,
+    filter: web::Query<ProjectScopeFilter>,
+    pagination: web::Query<Pagination>,
+) -> Result<HttpResponse, RepositoryError> {
+    let sort = vec![ProjectScopeSortOrder::new(
+        ProjectScopeSortableFields::Id,
+        SortOrder::Asc,
+    )];
+    let (items, next_cursor) = repository
+        .find(filter.into_inner(), Some(sort), Some(pagination.into_inner()))
+        .await?;
+    Ok(HttpResponse::Ok().json(Page { items, next_cursor }))
+}
+
+/// `rate_limiter` must be built once by the caller (e.g. alongside the repo's
+/// other shared `web::Data`) and passed in here on every worker's
+/// `configure_routes` call, so all workers throttle against the same bucket
+/// map instead of each getting its own.
+pub fn configure_routes(config: &mut actix_web::web::ServiceConfig, rate_limiter: RateLimiter) {
+    config.service(
+        web::scope("/project-scopes")
+            .wrap(CsrfConfig::from_env())
+            .wrap(rate_limiter)
+            .wrap(TracingLogger::default())
+            .service(
+                actix_web::web::resource("")
+                    .route(actix_web::web::post().to(post))
+                    .route(actix_web::web::get().to(list)),
+            )
+            .service(
+                actix_web::web::resource("/{id}")
+                    .route(actix_web::web::get().to(get))
+                    .route(actix_web::web::patch().to(patch))
+                    .route(actix_web::web::delete().to(delete)),
+            ),
+    );
+}
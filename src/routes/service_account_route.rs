@@ -1,12 +1,15 @@
-use crate::models::pagination::Pagination;
+use crate::middleware::csrf::CsrfConfig;
+use crate::middleware::ratelimit::RateLimiter;
+use crate::models::pagination::{Page, Pagination};
 use crate::models::service_account::{
     ServiceAccountCreatePayload, ServiceAccountFilter, ServiceAccountResponse,
     ServiceAccountSortOrder, ServiceAccountSortableFields, ServiceAccountUpdatePayload,
 };
 use crate::models::sort::SortOrder;
-use crate::repositories::base::Repository;
+use crate::repositories::base::{Repository, RepositoryError};
 use crate::repositories::service_account_repository::ServiceAccountRepository;
-use actix_web::{Error, HttpResponse, web};
+use actix_web::{HttpResponse, web};
+use tracing_actix_web::TracingLogger;
 
 #[utoipa::path(
     post,
@@ -21,11 +24,8 @@ use actix_web::{Error, HttpResponse, web};
 pub async fn post(
     repository: web::Data<ServiceAccountRepository>,
     payload: web::Json<ServiceAccountCreatePayload>,
-) -> Result<HttpResponse, Error> {
-    let service_account = repository
-        .create(payload.into_inner())
-        .await
-        .map_err(actix_web::error::ErrorBadRequest)?;
+) -> Result<HttpResponse, RepositoryError> {
+    let service_account = repository.create(payload.into_inner()).await?;
     Ok(HttpResponse::Created().json(service_account))
 }
 
@@ -44,11 +44,13 @@ pub async fn post(
 pub async fn get(
     repository: web::Data<ServiceAccountRepository>,
     id: web::Path<uuid::Uuid>,
-) -> Result<HttpResponse, Error> {
+) -> Result<HttpResponse, RepositoryError> {
     let service_account = repository
         .read(id.into_inner())
-        .await
-        .map_err(actix_web::error::ErrorNotFound)?;
+        .await?
+        .ok_or(RepositoryError::NotFound {
+            entity: "Service account",
+        })?;
     Ok(HttpResponse::Ok().json(service_account))
 }
 
@@ -72,29 +74,9 @@ pub async fn patch(
     repository: web::Data<ServiceAccountRepository>,
     id: web::Path<uuid::Uuid>,
     payload: web::Json<ServiceAccountUpdatePayload>,
-) -> Result<HttpResponse, Error> {
-    let service_account = repository
-        .update(id.into_inner(), payload.into_inner())
-        .await;
-    if service_account.is_err() {
-        let error_message = service_account.unwrap_err().to_string();
-        match error_message.as_str() {
-            "No changes to update" => return Err(actix_web::error::ErrorBadRequest(error_message)),
-            "Service account not found" => {
-                return Err(actix_web::error::ErrorNotFound(error_message));
-            }
-            "Service account name already exists" => {
-                return Err(actix_web::error::ErrorConflict(error_message));
-            }
-            "Service account email already exists" => {
-                return Err(actix_web::error::ErrorConflict(error_message));
-            }
-            _ => {
-                return Err(actix_web::error::ErrorInternalServerError(error_message));
-            }
-        }
-    }
-    Ok(HttpResponse::Ok().json(service_account.unwrap()))
+) -> Result<HttpResponse, RepositoryError> {
+    let service_account = repository.update(id.into_inner(), payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(service_account))
 }
 
 #[utoipa::path(
@@ -112,12 +94,13 @@ pub async fn patch(
 pub async fn delete(
     repository: web::Data<ServiceAccountRepository>,
     id: web::Path<uuid::Uuid>,
-) -> Result<HttpResponse, Error> {
-    let result = repository.delete(id.into_inner()).await;
-    match result {
-        Ok(true) => Ok(HttpResponse::NoContent().finish()),
-        Ok(false) => Err(actix_web::error::ErrorNotFound("Service account not found")),
-        Err(error) => Err(actix_web::error::ErrorInternalServerError(error)),
+) -> Result<HttpResponse, RepositoryError> {
+    if repository.delete(id.into_inner()).await? {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(RepositoryError::NotFound {
+            entity: "Service account",
+        })
     }
 }
 
@@ -126,39 +109,47 @@ pub async fn delete(
     path = "/service-accounts",
     tag = "Service Accounts",
     responses(
-        (status = 200, description = "Service accounts found", body = Vec<ServiceAccountResponse>),
+        (status = 200, description = "Service accounts found", body = Page<ServiceAccountResponse>),
+        (status = 400, description = "Invalid pagination cursor", body = String),
     ),
     params(
         ("name" = Option<String>, Query, description = "Filter service accounts by name"),
         ("description" = Option<String>, Query, description = "Filter service accounts by description"),
         ("enabled" = Option<bool>, Query, description = "Filter service accounts by enabled"),
-        ("offset" = Option<u32>, Query, description = "Offset for pagination"),
+        ("offset" = Option<u32>, Query, description = "Offset for pagination (ignored when cursor is set)"),
         ("limit" = Option<u32>, Query, description = "Number of items per page"),
+        ("cursor" = Option<String>, Query, description = "Opaque cursor returned as `next_cursor` by a previous call; resumes keyset pagination instead of using offset"),
     )
 )]
 pub async fn list(
     repository: web::Data<ServiceAccountRepository>,
     filter: web::Query<ServiceAccountFilter>,
     pagination: web::Query<Pagination>,
-) -> Result<HttpResponse, Error> {
+) -> Result<HttpResponse, RepositoryError> {
     let sort = vec![ServiceAccountSortOrder::new(
         ServiceAccountSortableFields::Id,
         SortOrder::Asc,
     )];
-    let service_accounts = repository
+    let (items, next_cursor) = repository
         .find(
             filter.into_inner(),
             Some(sort),
             Some(pagination.into_inner()),
         )
-        .await
-        .map_err(actix_web::error::ErrorInternalServerError)?;
-    Ok(HttpResponse::Ok().json(service_accounts))
+        .await?;
+    Ok(HttpResponse::Ok().json(Page { items, next_cursor }))
 }
 
-pub fn configure_routes(config: &mut actix_web::web::ServiceConfig) {
+/// `rate_limiter` must be built once by the caller (e.g. alongside the repo's
+/// other shared `web::Data`) and passed in here on every worker's
+/// `configure_routes` call, so all workers throttle against the same bucket
+/// map instead of each getting its own.
+pub fn configure_routes(config: &mut actix_web::web::ServiceConfig, rate_limiter: RateLimiter) {
     config.service(
         web::scope("/service-accounts")
+            .wrap(CsrfConfig::from_env())
+            .wrap(rate_limiter)
+            .wrap(TracingLogger::default())
             .service(
                 actix_web::web::resource("")
                     .route(actix_web::web::post().to(post))
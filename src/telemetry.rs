@@ -0,0 +1,41 @@
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// Output format for the global tracing subscriber.
+///
+/// `Pretty` is meant for local development; `Json` is meant for anywhere
+/// logs are shipped to a collector that expects one structured record per
+/// line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+impl LogFormat {
+    /// Reads `LOG_FORMAT` from the environment (`"json"` or `"pretty"`,
+    /// case-insensitive), defaulting to `Pretty` when unset or unrecognized.
+    pub fn from_env() -> Self {
+        match std::env::var("LOG_FORMAT") {
+            Ok(value) if value.eq_ignore_ascii_case("json") => LogFormat::Json,
+            _ => LogFormat::Pretty,
+        }
+    }
+}
+
+/// Initializes the global `tracing` subscriber. Call this once at startup,
+/// before the `actix_web::App` is built, so that `TracingLogger` and every
+/// `#[tracing::instrument]`'d repository method write through it.
+///
+/// The filter defaults to `info` and respects `RUST_LOG` when set.
+pub fn init(format: LogFormat) {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let registry = tracing_subscriber::fmt()
+        .with_env_filter(env_filter)
+        .with_span_events(FmtSpan::CLOSE);
+
+    match format {
+        LogFormat::Json => registry.json().init(),
+        LogFormat::Pretty => registry.pretty().init(),
+    }
+}
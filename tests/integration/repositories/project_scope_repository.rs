@@ -91,16 +91,14 @@ async fn test_project_scope_repository_read_existing_account_succeeds(pool: PgPo
 }
 
 #[sqlx::test]
-async fn test_project_scope_repository_read_nonexistent_account_returns_error(pool: PgPool) {
+async fn test_project_scope_repository_read_nonexistent_account_returns_none(pool: PgPool) {
     let repository = ProjectScopeRepository::new(Arc::new(pool));
 
     let project_id = Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap();
 
-    let project_scope = repository.read(project_id).await;
+    let project_scope = repository.read(project_id).await.unwrap();
 
-    assert!(project_scope.is_err());
-    let error_message = project_scope.unwrap_err().to_string();
-    assert_eq!(error_message, "Project scope not found");
+    assert!(project_scope.is_none());
 }
 
 #[sqlx::test(fixtures("../fixtures/projects.sql", "../fixtures/project_scopes.sql"))]
@@ -221,9 +219,10 @@ async fn test_project_scope_find_with_limit_pagination(pool: PgPool) {
     let pagination = Some(Pagination {
         limit: Some(2),
         offset: None,
+        cursor: None,
     });
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 2);
 }
@@ -237,9 +236,10 @@ async fn test_project_scope_find_with_offset_pagination(pool: PgPool) {
     let pagination = Some(Pagination {
         limit: None,
         offset: Some(1),
+        cursor: None,
     });
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 10);
 }
@@ -253,9 +253,10 @@ async fn test_project_scope_find_with_limit_offset_pagination(pool: PgPool) {
     let pagination = Some(Pagination {
         limit: Some(2),
         offset: Some(1),
+        cursor: None,
     });
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 2);
 }
@@ -271,7 +272,7 @@ async fn test_project_scope_find_with_project_id_filter(pool: PgPool) {
     let sort = None;
     let pagination = None;
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 6);
 }
@@ -287,7 +288,7 @@ async fn test_project_scope_find_with_scope_filter(pool: PgPool) {
     let sort = None;
     let pagination = None;
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 1);
 }
@@ -303,7 +304,7 @@ async fn test_project_scope_find_with_description_filter(pool: PgPool) {
     let sort = None;
     let pagination = None;
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 6);
 }
@@ -320,7 +321,7 @@ async fn test_project_scope_find_with_enabled_is_true_filter(pool: PgPool) {
     let sort = None;
     let pagination = None;
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 10);
 }
@@ -336,7 +337,92 @@ async fn test_project_scope_find_with_enabled_is_false_filter(pool: PgPool) {
     let sort = None;
     let pagination = None;
 
-    let project_scopes = repository.find(filter, sort, pagination).await.unwrap();
+    let (project_scopes, _next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
 
     assert_eq!(project_scopes.len(), 4);
 }
+
+#[sqlx::test(fixtures("../fixtures/projects.sql", "../fixtures/project_scopes.sql"))]
+async fn test_project_scope_find_with_limit_pagination_emits_next_cursor(pool: PgPool) {
+    let repository = ProjectScopeRepository::new(Arc::new(pool));
+
+    let filter = ProjectScopeFilter::default();
+    let sort = None;
+    let pagination = Some(Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: None,
+    });
+
+    let (project_scopes, next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
+
+    assert_eq!(project_scopes.len(), 2);
+    assert!(next_cursor.is_some());
+}
+
+#[sqlx::test(fixtures("../fixtures/projects.sql", "../fixtures/project_scopes.sql"))]
+async fn test_project_scope_find_on_last_page_has_no_next_cursor(pool: PgPool) {
+    let repository = ProjectScopeRepository::new(Arc::new(pool));
+
+    let filter = ProjectScopeFilter::default();
+    let sort = None;
+    let pagination = Some(Pagination {
+        limit: Some(Pagination::MAX_LIMIT),
+        offset: None,
+        cursor: None,
+    });
+
+    let (_project_scopes, next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
+
+    assert!(next_cursor.is_none());
+}
+
+#[sqlx::test(fixtures("../fixtures/projects.sql", "../fixtures/project_scopes.sql"))]
+async fn test_project_scope_find_with_cursor_resumes_after_previous_page(pool: PgPool) {
+    let pool = Arc::new(pool);
+    let repository = ProjectScopeRepository::new(pool.clone());
+
+    let first_page = Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: None,
+    };
+    let (first_items, next_cursor) = repository
+        .find(ProjectScopeFilter::default(), None, Some(first_page))
+        .await
+        .unwrap();
+    let next_cursor = next_cursor.expect("first page should have a next cursor");
+
+    let second_page = Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: Some(next_cursor),
+    };
+    let (second_items, _next_cursor) = repository
+        .find(ProjectScopeFilter::default(), None, Some(second_page))
+        .await
+        .unwrap();
+
+    let first_ids: Vec<_> = first_items.iter().map(|item| item.id).collect();
+    assert!(second_items.iter().all(|item| !first_ids.contains(&item.id)));
+    assert!(second_items.first().unwrap().id > first_items.last().unwrap().id);
+}
+
+#[sqlx::test(fixtures("../fixtures/projects.sql", "../fixtures/project_scopes.sql"))]
+async fn test_project_scope_find_with_invalid_cursor_fails(pool: PgPool) {
+    let repository = ProjectScopeRepository::new(Arc::new(pool));
+
+    let pagination = Some(Pagination {
+        limit: None,
+        offset: None,
+        cursor: Some("not-a-valid-cursor".to_string()),
+    });
+
+    let result = repository
+        .find(ProjectScopeFilter::default(), None, pagination)
+        .await;
+
+    assert!(result.is_err());
+    let error_message = result.unwrap_err().to_string();
+    assert_eq!(error_message, "Invalid pagination cursor");
+}
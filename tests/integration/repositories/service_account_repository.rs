@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use sentinel_guard::{
+    models::{pagination::Pagination, service_account::ServiceAccountFilter},
+    repositories::{base::Repository, service_account_repository::ServiceAccountRepository},
+};
+use sqlx::PgPool;
+
+#[sqlx::test(fixtures("../fixtures/service_accounts.sql"))]
+async fn test_service_account_find_with_limit_pagination_emits_next_cursor(pool: PgPool) {
+    let repository = ServiceAccountRepository::new(Arc::new(pool));
+
+    let filter = ServiceAccountFilter::default();
+    let sort = None;
+    let pagination = Some(Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: None,
+    });
+
+    let (service_accounts, next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
+
+    assert_eq!(service_accounts.len(), 2);
+    assert!(next_cursor.is_some());
+}
+
+#[sqlx::test(fixtures("../fixtures/service_accounts.sql"))]
+async fn test_service_account_find_on_last_page_has_no_next_cursor(pool: PgPool) {
+    let repository = ServiceAccountRepository::new(Arc::new(pool));
+
+    let filter = ServiceAccountFilter::default();
+    let sort = None;
+    let pagination = Some(Pagination {
+        limit: Some(Pagination::MAX_LIMIT),
+        offset: None,
+        cursor: None,
+    });
+
+    let (_service_accounts, next_cursor) = repository.find(filter, sort, pagination).await.unwrap();
+
+    assert!(next_cursor.is_none());
+}
+
+#[sqlx::test(fixtures("../fixtures/service_accounts.sql"))]
+async fn test_service_account_find_with_cursor_resumes_after_previous_page(pool: PgPool) {
+    let pool = Arc::new(pool);
+    let repository = ServiceAccountRepository::new(pool.clone());
+
+    let first_page = Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: None,
+    };
+    let (first_items, next_cursor) = repository
+        .find(ServiceAccountFilter::default(), None, Some(first_page))
+        .await
+        .unwrap();
+    let next_cursor = next_cursor.expect("first page should have a next cursor");
+
+    let second_page = Pagination {
+        limit: Some(2),
+        offset: None,
+        cursor: Some(next_cursor),
+    };
+    let (second_items, _next_cursor) = repository
+        .find(ServiceAccountFilter::default(), None, Some(second_page))
+        .await
+        .unwrap();
+
+    let first_ids: Vec<_> = first_items.iter().map(|item| item.id).collect();
+    assert!(second_items.iter().all(|item| !first_ids.contains(&item.id)));
+    assert!(second_items.first().unwrap().id > first_items.last().unwrap().id);
+}
+
+#[sqlx::test(fixtures("../fixtures/service_accounts.sql"))]
+async fn test_service_account_find_with_invalid_cursor_fails(pool: PgPool) {
+    let repository = ServiceAccountRepository::new(Arc::new(pool));
+
+    let pagination = Some(Pagination {
+        limit: None,
+        offset: None,
+        cursor: Some("not-a-valid-cursor".to_string()),
+    });
+
+    let result = repository
+        .find(ServiceAccountFilter::default(), None, pagination)
+        .await;
+
+    assert!(result.is_err());
+    let error_message = result.unwrap_err().to_string();
+    assert_eq!(error_message, "Invalid pagination cursor");
+}